@@ -7,10 +7,10 @@
 //! pointer casts or transmutation.
 //! The macro takes care of the unsafe code for generating the necessary
 //! conversion as well as convenient trait implementations.
-//! All generated functions for creating instances are private, so the user
-//! is free to implement further (public) constructor functions with potential
-//! additional invariant checks within the same module where the macro is
-//! invoked.
+//! All generated functions for creating instances are private by default, so
+//! the user is free to implement further (public) constructor functions with
+//! potential additional invariant checks within the same module where the
+//! macro is invoked.
 //!
 //! See the documentation of the [`slicewrap::wrap`] macro for details and
 //! examples.
@@ -25,7 +25,25 @@ extern crate alloc;
 pub mod __alloc {
     pub use alloc::boxed::Box;
     pub use alloc::rc::Rc;
+    pub use alloc::string::String;
     pub use alloc::sync::Arc;
+    pub use alloc::vec::Vec;
+}
+
+/// Expands the given items only if the `alloc` feature is enabled.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alloc_only {
+    ($($item:item)*) => { $($item)* };
+}
+
+/// Expands the given items only if the `alloc` feature is enabled.
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alloc_only {
+    ($($item:item)*) => {};
 }
 
 /// A macro for generating the (partially unsafe) boilerpate code required for
@@ -35,6 +53,8 @@ pub mod __alloc {
 /// For wrappers around [`str`], implementations for [`Display`](core::fmt::Display)
 /// as well as direct comparisons with raw strings are also generated for
 /// convenience.
+/// Wrappers around slices can likewise be compared directly with arrays of
+/// any length.
 ///
 /// # Examples
 ///
@@ -87,16 +107,323 @@ pub mod __alloc {
 /// }
 /// ```
 ///
+/// All options, such as `from` and the ones described below, are separated by
+/// commas and can be given in any order, but each one at most once.
+///
+/// By default, all generated constructors (`from_ref`, `from_ref_mut` and the
+/// smart pointer conversions `from_boxed`, `from_rc` and `from_arc`) are private.
+/// The `ctor_vis` option applies a different visibility to all of them, e.g.,
+/// for allowing a parent module to create instances:
+///
+/// ```
+/// mod protocol {
+///     pub mod wire {
+///         slicewrap::wrap!(
+///             pub struct Topic(str), from = [Box], ctor_vis = pub(super);
+///         );
+///     }
+///
+///     pub fn topic(string: &str) -> Box<wire::Topic> {
+///         wire::Topic::from_boxed(string.into())
+///     }
+/// }
+///
+/// assert_eq!(&protocol::topic("a/b")[..], "a/b");
+/// ```
+///
+/// Further implementations can be enabled through the `flags` option.
+/// The `hash` flag generates a [`Hash`](core::hash::Hash) implementation
+/// forwarding to the wrapped slice or string.
+/// Combined with `from = [Box]`, it also generates a
+/// [`Borrow`](core::borrow::Borrow) implementation of the inner type for
+/// `Box<Wrapper>`, so boxed wrappers can be looked up in hash maps by their raw
+/// contents.
+/// This requires the wrapper's `PartialEq` and `Eq` implementations to agree
+/// with those of the inner type as well, e.g., by deriving them:
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// slicewrap::wrap!(
+///     #[derive(PartialEq, Eq)]
+///     pub struct Key([u8]), from = [Box], flags = [hash];
+/// );
+///
+/// let mut map = HashMap::new();
+/// map.insert(Key::from_boxed(vec![1, 2, 3].into()), "value");
+/// assert_eq!(map.get(&[1, 2, 3][..]), Some(&"value"));
+/// ```
+///
+/// For slices of `Clone` elements, the `numeric` flag generates `sum` and
+/// `product` methods, which convert each element into the accumulator type
+/// first, so that e.g. a `[u32]` wrapper can be summed into a `u64`:
+///
+/// ```
+/// slicewrap::wrap!(struct Samples([u32]), flags = [numeric]);
+///
+/// let samples = Samples::from_ref(&[u32::MAX, 1]);
+/// assert_eq!(samples.sum::<u64>(), 1 << 32);
+/// ```
+///
+/// Instead of writing validating constructors manually, a validator function
+/// with the signature `fn(&Inner) -> Result<(), E>` can be passed through the
+/// `validate_fn` option, which requires its error type `E` to be given through
+/// the `error` option as well.
+/// This generates a public `new` constructor as well as a
+/// [`TryFrom`](core::convert::TryFrom) implementation for references, both of
+/// which call the validator before creating a wrapper.
+/// For each smart pointer type in the `from` option, the respective public
+/// `new_boxed`, `new_rc` or `new_arc` constructor is generated as well.
+/// With `from = [Box]`, boxed wrappers can also be created from an owned
+/// `String` or `Vec` through `TryFrom`, re-using its allocation if it has no
+/// excess capacity:
+///
+/// ```
+/// fn validate_short(string: &str) -> Result<(), usize> {
+///     if string.len() <= 8 { Ok(()) } else { Err(string.len()) }
+/// }
+///
+/// slicewrap::wrap!(
+///     #[derive(Debug)]
+///     pub struct ShortStr(str), from = [Box], validate_fn = validate_short, error = usize;
+/// );
+///
+/// assert_eq!(ShortStr::new("Hello").unwrap(), "Hello");
+/// assert_eq!(<&ShortStr>::try_from("Hello World").unwrap_err(), 11);
+/// assert_eq!(&ShortStr::new_boxed("Hello".into()).unwrap()[..], "Hello");
+/// let boxed = Box::<ShortStr>::try_from(String::from("Hello")).unwrap();
+/// assert_eq!(&boxed[..], "Hello");
+/// ```
+///
+/// The generated `new` constructor clashes with any inherent `new` method
+/// written for the same wrapper, so such a method has to be renamed when adding
+/// the `validate_fn` option.
+/// The validating constructors are public by default, the `validate_vis`
+/// option applies a different visibility to them, e.g., `pub(crate)` or
+/// `pub(self)` for private constructors.
+/// This does not affect the `TryFrom` implementations, which are always
+/// public:
+///
+/// ```
+/// mod ascii {
+///     fn validate_ascii(string: &str) -> Result<(), ()> {
+///         if string.is_ascii() { Ok(()) } else { Err(()) }
+///     }
+///
+///     slicewrap::wrap!(
+///         pub struct AsciiStr(str),
+///         validate_fn = validate_ascii,
+///         error = (),
+///         validate_vis = pub(crate);
+///     );
+/// }
+///
+/// assert!(ascii::AsciiStr::new("Grüße").is_err());
+/// ```
+///
 /// # Note
 ///
 /// It is currently not possible to wrap generic slices or slices of types with
 /// lifetimes.
 #[macro_export]
 macro_rules! wrap {
-    // entry point for `str` slice wrappers (generates extra conversion &
-    // comparison methods)
-    ($(#[$attr:meta])* $vis:vis struct $name:ident(str) $(, from = [$($from:ident),*])? $(;)?) => {
-        $crate::wrap!(@inner $(#[$attr])* $vis struct $name(str) $(, from = [$($from),*])?);
+    // entry point for all wrapper types, the options following the struct
+    // declaration are normalized into one slot per option before generating
+    // any code
+    ($(#[$attr:meta])* $vis:vis struct $name:ident $body:tt $($opts:tt)*) => {
+        $crate::wrap!(
+            @opts [] {$(#[$attr])* $vis struct $name $body} [] [] [] [] [] [] $($opts)*
+        );
+    };
+    // internal: entry point for `wrap_all`, which parses the shared options
+    // after the struct's own ones and merges them
+    (
+        @with_shared [$($shared:tt)*]
+        $(#[$attr:meta])* $vis:vis struct $name:ident $body:tt $($opts:tt)*
+    ) => {
+        $crate::wrap!(
+            @opts [shared $($shared)*] {$(#[$attr])* $vis struct $name $body}
+            [] [] [] [] [] [] $($opts)*
+        );
+    };
+    // internal: all options have been parsed
+    (
+        @opts [] $decl:tt
+        $from:tt $ctor_vis:tt $flags:tt $validate:tt $err:tt $validate_vis:tt $(;)?
+    ) => {
+        $crate::wrap!(@check $decl $from $ctor_vis $flags $validate $err $validate_vis);
+    };
+    (
+        @opts [shared] $decl:tt
+        $from:tt $ctor_vis:tt $flags:tt $validate:tt $err:tt $validate_vis:tt $(;)?
+    ) => {
+        $crate::wrap!(@check $decl $from $ctor_vis $flags $validate $err $validate_vis);
+    };
+    (
+        @opts [shared $($shared:tt)+] $decl:tt
+        $from:tt $ctor_vis:tt $flags:tt $validate:tt $err:tt $validate_vis:tt $(;)?
+    ) => {
+        $crate::wrap!(
+            @opts [merge [$from $ctor_vis $flags $validate $err $validate_vis]] $decl
+            [] [] [] [] [] [] , $($shared)+
+        );
+    };
+    (
+        @opts [merge $own:tt] $decl:tt
+        $from:tt $ctor_vis:tt $flags:tt $validate:tt $err:tt $validate_vis:tt
+    ) => {
+        $crate::wrap!(
+            @merge $decl $own [$from $ctor_vis $flags $validate $err $validate_vis] []
+        );
+    };
+    // internal: parses the `from` option
+    (
+        @opts $cont:tt $decl:tt [] $ctor_vis:tt $flags:tt $validate:tt $err:tt $validate_vis:tt
+        , from = [$($from:ident),*] $($rest:tt)*
+    ) => {
+        $crate::wrap!(
+            @opts $cont $decl [[$($from),*]] $ctor_vis $flags $validate $err $validate_vis
+            $($rest)*
+        );
+    };
+    // internal: parses the `ctor_vis` option
+    (
+        @opts $cont:tt $decl:tt $from:tt [] $flags:tt $validate:tt $err:tt $validate_vis:tt
+        , ctor_vis = pub ($($ctor_path:tt)*) $($rest:tt)*
+    ) => {
+        $crate::wrap!(
+            @opts $cont $decl $from [[pub ($($ctor_path)*)]] $flags $validate $err $validate_vis
+            $($rest)*
+        );
+    };
+    (
+        @opts $cont:tt $decl:tt $from:tt [] $flags:tt $validate:tt $err:tt $validate_vis:tt
+        , ctor_vis = pub $($rest:tt)*
+    ) => {
+        $crate::wrap!(
+            @opts $cont $decl $from [[pub]] $flags $validate $err $validate_vis $($rest)*
+        );
+    };
+    // internal: parses the `flags` option
+    (
+        @opts $cont:tt $decl:tt $from:tt $ctor_vis:tt [] $validate:tt $err:tt $validate_vis:tt
+        , flags = [$($flag:ident),*] $($rest:tt)*
+    ) => {
+        $crate::wrap!(
+            @opts $cont $decl $from $ctor_vis [[$($flag),*]] $validate $err $validate_vis
+            $($rest)*
+        );
+    };
+    // internal: parses the `validate_fn` option
+    (
+        @opts $cont:tt $decl:tt $from:tt $ctor_vis:tt $flags:tt [] $err:tt $validate_vis:tt
+        , validate_fn = $validate:path $(, $($rest:tt)*)?
+    ) => {
+        $crate::wrap!(
+            @opts $cont $decl $from $ctor_vis $flags [[$validate]] $err $validate_vis
+            $(, $($rest)*)?
+        );
+    };
+    (
+        @opts $cont:tt $decl:tt $from:tt $ctor_vis:tt $flags:tt [] $err:tt $validate_vis:tt
+        , validate_fn = $validate:path;
+    ) => {
+        $crate::wrap!(
+            @opts $cont $decl $from $ctor_vis $flags [[$validate]] $err $validate_vis
+        );
+    };
+    // internal: parses the `error` option
+    (
+        @opts $cont:tt $decl:tt $from:tt $ctor_vis:tt $flags:tt $validate:tt [] $validate_vis:tt
+        , error = $err:ty $(, $($rest:tt)*)?
+    ) => {
+        $crate::wrap!(
+            @opts $cont $decl $from $ctor_vis $flags $validate [[$err]] $validate_vis
+            $(, $($rest)*)?
+        );
+    };
+    (
+        @opts $cont:tt $decl:tt $from:tt $ctor_vis:tt $flags:tt $validate:tt [] $validate_vis:tt
+        , error = $err:ty;
+    ) => {
+        $crate::wrap!(@opts $cont $decl $from $ctor_vis $flags $validate [[$err]] $validate_vis);
+    };
+    // internal: parses the `validate_vis` option
+    (
+        @opts $cont:tt $decl:tt $from:tt $ctor_vis:tt $flags:tt $validate:tt $err:tt []
+        , validate_vis = pub ($($validate_path:tt)*) $($rest:tt)*
+    ) => {
+        $crate::wrap!(
+            @opts $cont $decl $from $ctor_vis $flags $validate $err [[pub ($($validate_path)*)]]
+            $($rest)*
+        );
+    };
+    (
+        @opts $cont:tt $decl:tt $from:tt $ctor_vis:tt $flags:tt $validate:tt $err:tt []
+        , validate_vis = pub $($rest:tt)*
+    ) => {
+        $crate::wrap!(
+            @opts $cont $decl $from $ctor_vis $flags $validate $err [[pub]] $($rest)*
+        );
+    };
+    (
+        @opts $cont:tt $decl:tt
+        $from:tt $ctor_vis:tt $flags:tt $validate:tt $err:tt $validate_vis:tt
+        , $key:ident $($rest:tt)*
+    ) => {
+        compile_error!(concat!("invalid or duplicate `", stringify!($key), "` option"));
+    };
+    (@opts $($rest:tt)*) => {
+        compile_error!("expected `,` followed by an option or the end of the declaration");
+    };
+    // internal: takes each option from the struct's own options if given and
+    // from the shared options otherwise
+    (@merge $decl:tt [[] $($own:tt)*] [$shared:tt $($rest:tt)*] [$($slots:tt)*]) => {
+        $crate::wrap!(@merge $decl [$($own)*] [$($rest)*] [$($slots)* $shared]);
+    };
+    (@merge $decl:tt [$own:tt $($owns:tt)*] [$shared:tt $($rest:tt)*] [$($slots:tt)*]) => {
+        $crate::wrap!(@merge $decl [$($owns)*] [$($rest)*] [$($slots)* $own]);
+    };
+    (@merge $decl:tt [] [] [$($slots:tt)*]) => {
+        $crate::wrap!(@check $decl $($slots)*);
+    };
+    // internal: checks that the validation options are given together and
+    // combines them into a single slot, the validating constructors are
+    // public unless specified otherwise
+    (@check $decl:tt $from:tt $ctor_vis:tt $flags:tt [] [] []) => {
+        $crate::wrap!(@emit $decl $from $ctor_vis $flags []);
+    };
+    (
+        @check $decl:tt $from:tt $ctor_vis:tt $flags:tt
+        [[$validate:path]] [[$err:ty]] []
+    ) => {
+        $crate::wrap!(@emit $decl $from $ctor_vis $flags [[$validate, $err, [pub]]]);
+    };
+    (
+        @check $decl:tt $from:tt $ctor_vis:tt $flags:tt
+        [[$validate:path]] [[$err:ty]] [$validate_vis:tt]
+    ) => {
+        $crate::wrap!(@emit $decl $from $ctor_vis $flags [[$validate, $err, $validate_vis]]);
+    };
+    (@check $decl:tt $from:tt $ctor_vis:tt $flags:tt [$validate:tt] [] $validate_vis:tt) => {
+        compile_error!("the `validate_fn` option requires an `error` option");
+    };
+    (@check $decl:tt $from:tt $ctor_vis:tt $flags:tt [] [$err:tt] $validate_vis:tt) => {
+        compile_error!("the `error` option requires a `validate_fn` option");
+    };
+    (@check $decl:tt $from:tt $ctor_vis:tt $flags:tt [] [] [$validate_vis:tt]) => {
+        compile_error!("the `validate_vis` option requires a `validate_fn` option");
+    };
+    // internal: generates a `str` wrapper (with extra conversion & comparison
+    // methods)
+    (
+        @emit {$(#[$attr:meta])* $vis:vis struct $name:ident (str)}
+        $from:tt $ctor_vis:tt $flags:tt $validate:tt
+    ) => {
+        $crate::wrap!(
+            @inner $(#[$attr])* $vis struct $name (str) [str]
+            $from $ctor_vis $flags $validate
+        );
 
         impl AsRef<[u8]> for $name {
             fn as_ref(&self) -> &[u8] {
@@ -118,24 +445,108 @@ macro_rules! wrap {
 
         impl core::fmt::Display for $name {
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                f.write_str(&self.0)
+                f.pad(&self.0)
             }
         }
     };
-    // entry point for any slice wrapper type
-    ($(#[$attr:meta])* $vis:vis struct $name:ident([$type:ty]) $(, from = [$($from:ident),*])? $(;)?) => {
-        $crate::wrap!(@inner $(#[$attr])* $vis struct $name ([$type]) $(, from = [$($from),*])?);
+    // internal: generates any other slice wrapper
+    (
+        @emit {$(#[$attr:meta])* $vis:vis struct $name:ident ([$type:ty])}
+        $from:tt $ctor_vis:tt $flags:tt $validate:tt
+    ) => {
+        $crate::wrap!(
+            @inner $(#[$attr])* $vis struct $name ([$type]) [slice $type]
+            $from $ctor_vis $flags $validate
+        );
+
+        $crate::__alloc_only! {
+            impl From<&$name> for $crate::__alloc::Vec<$type>
+            where
+                // the unused lifetime turns this into a non-trivial bound that is
+                // only checked on use, so non-`Clone` elements still compile
+                for<'a> $type: Clone,
+            {
+                fn from(reference: &$name) -> $crate::__alloc::Vec<$type> {
+                    reference.0.to_vec()
+                }
+            }
+        }
+
+        impl<U, const N: usize> core::cmp::PartialEq<[U; N]> for $name
+        where
+            $type: core::cmp::PartialEq<U>,
+        {
+            fn eq(&self, other: &[U; N]) -> bool {
+                self.0 == other[..]
+            }
+        }
+
+        impl<U, const N: usize> core::cmp::PartialEq<$name> for [U; N]
+        where
+            U: core::cmp::PartialEq<$type>,
+        {
+            fn eq(&self, other: &$name) -> bool {
+                self[..] == other.0
+            }
+        }
     };
     // internal: generates base declarations and then any optional conversions
-    (@inner $(#[$attr:meta])* $vis:vis struct $name:ident ($type:ty) $(, from = [$($from:ident),*])? $(;)?) => {
-        $crate::wrap!(@inner_base $(#[$attr])* $vis struct $name ($type));
-
+    (
+        @inner $(#[$attr:meta])* $vis:vis struct $name:ident ($type:ty) $kind:tt
+        $from:tt [$([$($ctor_vis:tt)*])?] $flags:tt $validate:tt
+    ) => {
+        $crate::wrap!(
+            @inner_base $(#[$attr])* $vis struct $name ($type) [$($($ctor_vis)*)?]
+        );
+        $crate::wrap!(@inner_froms $name [$($($ctor_vis)*)?] $type, $from);
+        $crate::wrap!(@inner_flags $name $type, $kind $from $flags);
+        $crate::wrap!(@inner_validates $name $type, $kind $from $validate);
+    };
+    // internal: generates the conversions for each given smart pointer type
+    (@inner_froms $name:ident $ctor_vis:tt $type:ty, [$([$($from:ident),*])?]) => {
         $($(
-            $crate::wrap!(@inner_from $name $from $type);
+            $crate::wrap!(@inner_from $name $from $ctor_vis $type);
+        )*)?
+    };
+    // internal: generates the implementations for each given flag
+    (@inner_flags $name:ident $type:ty, $kind:tt $from:tt [$([$($flag:ident),*])?]) => {
+        $($(
+            $crate::wrap!(@inner_flag $name $flag $type, $kind $from);
+        )*)?
+    };
+    // internal: expands the given items only if `Box` is in the `from` list
+    (@if_boxed [[Box $(, $from:ident)*]] { $($item:tt)* }) => {
+        $($item)*
+    };
+    (@if_boxed [[$other:ident $(, $from:ident)*]] $items:tt) => {
+        $crate::wrap!(@if_boxed [[$($from),*]] $items);
+    };
+    (@if_boxed $from:tt $items:tt) => {};
+    // internal: generates validating constructors, if requested
+    (
+        @inner_validates $name:ident $type:ty, $kind:tt $from:tt
+        [$([$validate:path, $err:ty, $validate_vis:tt])?]
+    ) => {
+        $(
+            $crate::wrap!(@inner_validate $name $validate_vis $type, $validate, $err);
+            $crate::wrap!(
+                @inner_validate_froms $name $validate_vis $type, $validate, $err, $kind $from
+            );
+        )?
+    };
+    // internal: generates validating conversions for each smart pointer type
+    (
+        @inner_validate_froms $name:ident $validate_vis:tt $type:ty, $validate:path, $err:ty,
+        $kind:tt [$([$($from:ident),*])?]
+    ) => {
+        $($(
+            $crate::wrap!(
+                @inner_validate_from $name $from $validate_vis $type, $validate, $err, $kind
+            );
         )*)?
     };
     // internal: generates base declarations
-    (@inner_base $(#[$attr:meta])* $vis:vis struct $name:ident ($type:ty)) => {
+    (@inner_base $(#[$attr:meta])* $vis:vis struct $name:ident ($type:ty) [$($ctor_vis:tt)*]) => {
         $(#[$attr])*
         //#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
         #[repr(transparent)]
@@ -143,13 +554,13 @@ macro_rules! wrap {
 
         impl $name {
             #[allow(unused)]
-            const fn from_ref(reference: &$type) -> &Self {
+            $($ctor_vis)* const fn from_ref(reference: &$type) -> &Self {
                 // SAFETY: the wrapper is a transparent newtype
                 unsafe { &*(reference as *const $type as *const Self) }
             }
 
             #[allow(unused)]
-            fn from_ref_mut(reference: &mut $type) -> &mut Self {
+            $($ctor_vis)* fn from_ref_mut(reference: &mut $type) -> &mut Self {
                 // SAFETY: the wrapper is a transparent newtype
                 unsafe { &mut *(reference as *mut $type as *mut Self) }
             }
@@ -190,9 +601,10 @@ macro_rules! wrap {
         }
     };
     // generates from/into functions for conversion of `Box` slices
-    (@inner_from $name:ident Box $type:ty) => {
+    (@inner_from $name:ident Box [$($ctor_vis:tt)*] $type:ty) => {
         impl $name {
-            const fn from_boxed(
+            #[allow(unused)]
+            $($ctor_vis)* const fn from_boxed(
                 boxed: $crate::__alloc::Box<$type>
             ) -> $crate::__alloc::Box<Self>
             {
@@ -216,9 +628,10 @@ macro_rules! wrap {
         }
     };
     // generates from/into functions for conversion of `Rc` slices
-    (@inner_from $name:ident Rc $type:ty) => {
+    (@inner_from $name:ident Rc [$($ctor_vis:tt)*] $type:ty) => {
         impl $name {
-            const fn from_rc(
+            #[allow(unused)]
+            $($ctor_vis)* const fn from_rc(
                 rc: $crate::__alloc::Rc<$type>
             ) -> $crate::__alloc::Rc<Self> {
                 unsafe { core::mem::transmute(rc) }
@@ -233,9 +646,10 @@ macro_rules! wrap {
         }
     };
     // generates from/into functions for conversion of `Arc` slices
-    (@inner_from $name:ident Arc $type:ty) => {
+    (@inner_from $name:ident Arc [$($ctor_vis:tt)*] $type:ty) => {
         impl $name {
-            const fn from_arc(
+            #[allow(unused)]
+            $($ctor_vis)* const fn from_arc(
                 arc: $crate::__alloc::Arc<$type>
             ) -> $crate::__alloc::Arc<Self> {
                 unsafe { core::mem::transmute(arc) }
@@ -248,7 +662,243 @@ macro_rules! wrap {
                 unsafe { core::mem::transmute(self) }
             }
         }
-    }
+    };
+    // generates validating constructors calling the given validator function
+    (
+        @inner_validate $name:ident [$($validate_vis:tt)*] $type:ty, $validate:path, $err:ty
+    ) => {
+        impl $name {
+            #[allow(unused)]
+            $($validate_vis)* fn new(inner: &$type) -> Result<&Self, $err> {
+                $validate(inner).map(|_| Self::from_ref(inner))
+            }
+        }
+
+        impl<'a> core::convert::TryFrom<&'a $type> for &'a $name {
+            type Error = $err;
+
+            fn try_from(inner: &'a $type) -> Result<Self, Self::Error> {
+                $name::new(inner)
+            }
+        }
+    };
+    // generates validating conversions from `Box` slices and their owned
+    // counterparts
+    (
+        @inner_validate_from $name:ident Box [$($validate_vis:tt)*] $type:ty,
+        $validate:path, $err:ty, $kind:tt
+    ) => {
+        impl $name {
+            #[allow(unused)]
+            $($validate_vis)* fn new_boxed(
+                boxed: $crate::__alloc::Box<$type>
+            ) -> Result<$crate::__alloc::Box<Self>, $err>
+            {
+                $validate(&boxed).map(|_| Self::from_boxed(boxed))
+            }
+        }
+
+        $crate::wrap!(@inner_validate_owned $name, $validate, $err, $kind);
+    };
+    // generates a validating conversion from `String`s into boxed wrappers
+    (@inner_validate_owned $name:ident, $validate:path, $err:ty, [str]) => {
+        impl core::convert::TryFrom<$crate::__alloc::String> for $crate::__alloc::Box<$name> {
+            type Error = $err;
+
+            fn try_from(string: $crate::__alloc::String) -> Result<Self, Self::Error> {
+                $validate(&string).map(|_| $name::from_boxed(string.into_boxed_str()))
+            }
+        }
+    };
+    // generates a validating conversion from `Vec`s into boxed wrappers
+    (@inner_validate_owned $name:ident, $validate:path, $err:ty, [slice $elem:ty]) => {
+        impl core::convert::TryFrom<$crate::__alloc::Vec<$elem>> for $crate::__alloc::Box<$name> {
+            type Error = $err;
+
+            fn try_from(vec: $crate::__alloc::Vec<$elem>) -> Result<Self, Self::Error> {
+                $validate(&vec).map(|_| $name::from_boxed(vec.into_boxed_slice()))
+            }
+        }
+    };
+    // generates a validating conversion from `Rc` slices
+    (
+        @inner_validate_from $name:ident Rc [$($validate_vis:tt)*] $type:ty,
+        $validate:path, $err:ty, $kind:tt
+    ) => {
+        impl $name {
+            #[allow(unused)]
+            $($validate_vis)* fn new_rc(
+                rc: $crate::__alloc::Rc<$type>
+            ) -> Result<$crate::__alloc::Rc<Self>, $err> {
+                $validate(&rc).map(|_| Self::from_rc(rc))
+            }
+        }
+    };
+    // generates a validating conversion from `Arc` slices
+    (
+        @inner_validate_from $name:ident Arc [$($validate_vis:tt)*] $type:ty,
+        $validate:path, $err:ty, $kind:tt
+    ) => {
+        impl $name {
+            #[allow(unused)]
+            $($validate_vis)* fn new_arc(
+                arc: $crate::__alloc::Arc<$type>
+            ) -> Result<$crate::__alloc::Arc<Self>, $err> {
+                $validate(&arc).map(|_| Self::from_arc(arc))
+            }
+        }
+    };
+    // generates a `Hash` implementation forwarding to the wrapped type and,
+    // for boxed wrappers, a matching `Borrow` implementation
+    (@inner_flag $name:ident hash $type:ty, $kind:tt $from:tt) => {
+        impl core::hash::Hash for $name {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                core::hash::Hash::hash(&self.0, state)
+            }
+        }
+
+        $crate::wrap!(@if_boxed $from {
+            impl core::borrow::Borrow<$type> for $crate::__alloc::Box<$name> {
+                fn borrow(&self) -> &$type {
+                    &self.0
+                }
+            }
+        });
+    };
+    // generates `sum` and `product` methods for slices of numeric types
+    (@inner_flag $name:ident numeric $type:ty, [slice $elem:ty] $from:tt) => {
+        impl $name {
+            /// Returns the sum of all elements, each converted into `S` before
+            /// accumulating, so that e.g. `u32` elements can be summed into a
+            /// `u64` without overflowing.
+            #[allow(unused)]
+            pub fn sum<S>(&self) -> S
+            where
+                S: core::iter::Sum<S> + From<$elem>,
+                $elem: Clone,
+            {
+                self.0.iter().cloned().map(S::from).sum()
+            }
+
+            /// Returns the product of all elements, each converted into `S`
+            /// before accumulating.
+            #[allow(unused)]
+            pub fn product<S>(&self) -> S
+            where
+                S: core::iter::Product<S> + From<$elem>,
+                $elem: Clone,
+            {
+                self.0.iter().cloned().map(S::from).product()
+            }
+        }
+    };
+    (@inner_flag $name:ident numeric $type:ty, [str] $from:tt) => {
+        compile_error!("the `numeric` flag is only supported for slice wrappers");
+    };
+    (@inner_flag $name:ident $flag:ident $($rest:tt)*) => {
+        compile_error!(concat!("unknown flag `", stringify!($flag), "`"));
+    };
+}
+
+/// A macro for generating multiple wrapper types sharing the same options.
+///
+/// The shared options (e.g., `from = [Box, Arc]`) are given first and
+/// terminated by a `;`, followed by any number of `;` separated struct
+/// declarations in the same form as accepted by [`wrap`].
+/// Each struct declaration is passed to [`wrap`] along with the shared options.
+/// A declaration may also specify its own options, each of which then replaces
+/// the respective shared option, while all other shared options still apply.
+///
+/// # Examples
+///
+/// ```
+/// slicewrap::wrap_all!(
+///     from = [Box, Arc];
+///     /// A protocol string.
+///     #[derive(Debug)]
+///     pub struct ProtoStr(str);
+///     pub struct ProtoBytes([u8]);
+///     // overrides the shared `from` option
+///     pub struct ProtoTopic(str), from = [Rc];
+///     // adds a `hash` flag and keeps the shared `from` option
+///     #[derive(PartialEq, Eq)]
+///     pub struct ProtoKey(str), flags = [hash];
+/// );
+///
+/// let boxed = ProtoStr::from_boxed("foo".into());
+/// let arc = ProtoBytes::from_arc([0, 1, 2][..].into());
+/// let rc = ProtoTopic::from_rc("a/b".into());
+/// let key = ProtoKey::from_arc("key".into());
+/// ```
+#[macro_export]
+macro_rules! wrap_all {
+    // internal: collects the shared options up to the first `;`
+    (@shared [$($shared:tt)*] ; $($rest:tt)*) => {
+        $crate::wrap_all!(@decl [$($shared)*] $($rest)*);
+    };
+    (@shared [$($shared:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::wrap_all!(@shared [$($shared)* $next] $($rest)*);
+    };
+    (@shared [$($shared:tt)*]) => {
+        compile_error!("expected `;` after the shared options");
+    };
+    // internal: generates a declaration without own options
+    (@decl [$($shared:tt)*]) => {};
+    (
+        @decl [$($shared:tt)*]
+        $(#[$attr:meta])* $vis:vis struct $name:ident $body:tt
+        $(; $($rest:tt)*)?
+    ) => {
+        $crate::wrap!(@with_shared [$($shared)*] $(#[$attr])* $vis struct $name $body);
+        $crate::wrap_all!(@decl [$($shared)*] $($($rest)*)?);
+    };
+    // internal: generates a declaration with own options in a single step, if
+    // each option value is a single token tree, a path or a restricted
+    // visibility
+    (
+        @decl [$($shared:tt)*]
+        $(#[$attr:meta])* $vis:vis struct $name:ident $body:tt
+        $(, $key:ident = $val:tt $(($($vis_path:tt)*))? $(:: $seg:tt)*)+
+        $(; $($rest:tt)*)?
+    ) => {
+        $crate::wrap!(
+            @with_shared [$($shared)*] $(#[$attr])* $vis struct $name $body
+            $(, $key = $val $(($($vis_path)*))? $(:: $seg)*)+
+        );
+        $crate::wrap_all!(@decl [$($shared)*] $($($rest)*)?);
+    };
+    // internal: collects the options of a declaration with any other own
+    // options token by token
+    (
+        @decl [$($shared:tt)*]
+        $(#[$attr:meta])* $vis:vis struct $name:ident $body:tt
+        , $($rest:tt)*
+    ) => {
+        $crate::wrap_all!(
+            @own [$($shared)*] [$(#[$attr])* $vis struct $name $body] [] $($rest)*
+        );
+    };
+    (@decl [$($shared:tt)*] $($rest:tt)*) => {
+        compile_error!("expected a struct declaration");
+    };
+    (@own [$($shared:tt)*] [$($decl:tt)*] [$($own:tt)*] ; $($rest:tt)*) => {
+        $crate::wrap!(@with_shared [$($shared)*] $($decl)*, $($own)*);
+        $crate::wrap_all!(@decl [$($shared)*] $($rest)*);
+    };
+    (@own [$($shared:tt)*] [$($decl:tt)*] [$($own:tt)*]) => {
+        $crate::wrap!(@with_shared [$($shared)*] $($decl)*, $($own)*);
+    };
+    (@own $shared:tt $decl:tt [$($own:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::wrap_all!(@own $shared $decl [$($own)* $next] $($rest)*);
+    };
+    ($(#[$attr:meta])* $vis:vis struct $($rest:tt)*) => {
+        compile_error!(
+            "expected the shared options followed by `;` before the first struct declaration"
+        );
+    };
+    ($($input:tt)*) => {
+        $crate::wrap_all!(@shared [] $($input)*);
+    };
 }
 
 /// A macro for making matchin on optional wrapped types created with
@@ -296,9 +946,80 @@ macro_rules! as_deref {
     }};
 }
 
+/// A macro for applying [`as_deref`] to each element of a slice or iterator of
+/// optional wrapped types.
+///
+/// The macro argument can be anything implementing [`IntoIterator`] with items
+/// of type `Option<&Wrapper>` or `&Option<&Wrapper>` and yields an iterator
+/// over the corresponding `Option<&Inner>` items.
+///
+/// # Examples
+///
+/// ```
+/// slicewrap::wrap!(struct Str(str));
+///
+/// let opts = [Some(Str::from_ref("foo")), None];
+/// let inner: Vec<Option<&str>> = slicewrap::as_deref_all!(&opts).collect();
+/// assert_eq!(inner, [Some("foo"), None]);
+/// ```
+#[macro_export]
+macro_rules! as_deref_all {
+    ($wraps:expr) => {
+        core::iter::IntoIterator::into_iter($wraps).map(|opt| $crate::as_deref!(opt))
+    };
+}
+
+/// A macro for projecting an optional wrapped type into another wrapped type
+/// through a fallible conversion of its inner slice.
+///
+/// For `Some(wrapper)`, the given function or closure is called with the
+/// wrapper's inner slice and its result is returned wrapped in `Some`, i.e.,
+/// for a conversion returning `Result<&New, E>`, the macro yields an
+/// `Option<Result<&New, E>>`.
+/// Use [`Option::transpose`] to turn this into a `Result<Option<&New>, E>`.
+///
+/// # Examples
+///
+/// ```
+/// slicewrap::wrap!(struct Str(str));
+/// slicewrap::wrap!(#[derive(Debug, PartialEq)] struct AsciiStr(str));
+///
+/// impl AsciiStr {
+///     fn new(string: &str) -> Result<&Self, &str> {
+///         if string.is_ascii() { Ok(Self::from_ref(string)) } else { Err(string) }
+///     }
+/// }
+///
+/// let opt = Some(Str::from_ref("foo"));
+/// let res = slicewrap::try_map_deref!(opt, AsciiStr::new);
+/// assert_eq!(res, Some(Ok(AsciiStr::from_ref("foo"))));
+///
+/// let opt: Option<&Str> = None;
+/// let res = slicewrap::try_map_deref!(opt, |inner| AsciiStr::new(inner));
+/// assert_eq!(res.transpose(), Ok(None));
+/// ```
+///
+/// For passing a mutable reference to the conversion, prepend `mut` to the
+/// first macro argument.
+#[macro_export]
+macro_rules! try_map_deref {
+    ($wrap:expr, $f:expr) => {{
+        match $wrap {
+            Some(ref inner) => Some(($f)(inner.as_inner())),
+            None => None,
+        }
+    }};
+    (mut $wrap:expr, $f:expr) => {{
+        match $wrap {
+            Some(ref mut inner) => Some(($f)(inner.as_inner_mut())),
+            None => None,
+        }
+    }};
+}
+
 #[cfg(test)]
 mod tests {
-    use std::{rc::Rc, sync::Arc};
+    use std::{collections::HashMap, rc::Rc, sync::Arc};
 
     super::wrap!(
         /// Some documentation
@@ -310,7 +1031,146 @@ mod tests {
         #[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
         struct Heapable(str), from = [Box, Rc]
     );
-    super::wrap!(pub struct SliceWrap([u8]), from = [Arc, Box, Rc]);
+    super::wrap!(
+        #[derive(Debug)]
+        pub struct SliceWrap([u8]), from = [Arc, Box, Rc]
+    );
+
+    super::wrap!(struct Numbers([u32]), flags = [numeric]);
+
+    super::wrap!(
+        #[derive(Debug, PartialEq, Eq)]
+        struct HashSlice([u8]), flags = [hash], from = [Box]
+    );
+
+    super::wrap!(
+        #[derive(Debug, PartialEq)]
+        struct Lowercase(str)
+    );
+
+    impl Lowercase {
+        fn new(string: &str) -> Result<&Self, &str> {
+            if string.chars().all(char::is_lowercase) {
+                Ok(Self::from_ref(string))
+            } else {
+                Err(string)
+            }
+        }
+
+        fn new_mut(string: &mut str) -> Option<&mut Self> {
+            if string.chars().all(char::is_lowercase) {
+                Some(Self::from_ref_mut(string))
+            } else {
+                None
+            }
+        }
+    }
+
+    mod shared {
+        crate::wrap_all!(
+            from = [Box, Arc];
+            #[derive(Debug, PartialEq)]
+            pub struct ProtoStr(str);
+            pub struct ProtoBytes([u8]);
+            pub struct ProtoTopic(str), from = [Rc], ctor_vis = pub(super);
+            pub struct ProtoName(str), ctor_vis = pub(super);
+        );
+
+        pub fn boxed_str(string: &str) -> Box<ProtoStr> {
+            ProtoStr::from_boxed(string.into())
+        }
+
+        pub fn arc_bytes(bytes: &[u8]) -> std::sync::Arc<ProtoBytes> {
+            ProtoBytes::from_arc(bytes.into())
+        }
+    }
+
+    // a list of declarations with own options, which must not exceed the
+    // recursion limit
+    mod properties {
+        crate::wrap_all!(
+            from = [Box, Arc];
+            #[derive(PartialEq, Eq)]
+            pub struct ClientId(str), flags = [hash], ctor_vis = pub(super);
+            #[derive(PartialEq, Eq)]
+            pub struct Username(str), flags = [hash], ctor_vis = pub(super);
+            #[derive(PartialEq, Eq)]
+            pub struct Password([u8]), flags = [hash], ctor_vis = pub(super);
+            #[derive(PartialEq, Eq)]
+            pub struct TopicName(str), flags = [hash], ctor_vis = pub(super);
+            #[derive(PartialEq, Eq)]
+            pub struct TopicFilter(str), flags = [hash], ctor_vis = pub(super);
+            #[derive(PartialEq, Eq)]
+            pub struct ContentType(str), flags = [hash], ctor_vis = pub(super);
+            #[derive(PartialEq, Eq)]
+            pub struct ResponseTopic(str), flags = [hash], ctor_vis = pub(super);
+            #[derive(PartialEq, Eq)]
+            pub struct CorrelationData([u8]), flags = [hash], ctor_vis = pub(super);
+            #[derive(PartialEq, Eq)]
+            pub struct ReasonString(str), flags = [hash], ctor_vis = pub(super);
+            #[derive(PartialEq, Eq)]
+            pub struct AuthMethod(str), flags = [hash], ctor_vis = pub(super);
+            #[derive(PartialEq, Eq)]
+            pub struct AuthData([u8]), flags = [hash], ctor_vis = pub(super);
+            #[derive(PartialEq, Eq)]
+            pub struct UserKey(str), flags = [hash], ctor_vis = pub(super);
+            #[derive(PartialEq, Eq)]
+            pub struct UserValue(str), flags = [hash], ctor_vis = pub(super);
+            #[derive(PartialEq, Eq)]
+            pub struct ServerReference(str), flags = [hash], ctor_vis = pub(super);
+            #[derive(PartialEq, Eq)]
+            pub struct AssignedClientId(str), flags = [hash], ctor_vis = pub(super);
+            #[derive(PartialEq, Eq)]
+            pub struct ResponseInfo(str), flags = [hash], ctor_vis = pub(super);
+            #[derive(Debug)]
+            pub(super) struct WillTopic(str),
+            validate_fn = super::validate_topic,
+            error = super::TopicError,
+            validate_vis = pub(super);
+        );
+    }
+
+    super::wrap!(
+        #[derive(Debug, PartialEq, Eq)]
+        struct MqttStr(str), from = [Box], flags = [hash]
+    );
+
+    #[derive(Debug, PartialEq)]
+    enum TopicError {
+        Empty,
+        Wildcard,
+    }
+
+    fn validate_topic(topic: &str) -> Result<(), TopicError> {
+        if topic.is_empty() {
+            Err(TopicError::Empty)
+        } else if topic.contains(['#', '+']) {
+            Err(TopicError::Wildcard)
+        } else {
+            Ok(())
+        }
+    }
+
+    super::wrap!(
+        #[derive(Debug)]
+        struct ValidTopic(str),
+        from = [Box, Rc, Arc],
+        validate_fn = validate_topic,
+        error = TopicError
+    );
+
+    fn validate_short(bytes: &[u8]) -> Result<(), usize> {
+        if bytes.len() <= 4 {
+            Ok(())
+        } else {
+            Err(bytes.len())
+        }
+    }
+
+    // `error` may also precede `validate_fn`
+    super::wrap!(
+        struct ShortBytes([u8]), from = [Box], error = usize, validate_fn = validate_short
+    );
 
     impl Heapable {
         fn to_boxed(&self) -> Box<Self> {
@@ -318,6 +1178,23 @@ mod tests {
         }
     }
 
+    mod protocol {
+        pub mod wire {
+            crate::wrap!(
+                #[derive(Debug, PartialEq)]
+                pub struct Topic(str), from = [Box], ctor_vis = pub(in crate::tests::protocol)
+            );
+        }
+
+        pub fn topic(string: &str) -> &wire::Topic {
+            wire::Topic::from_ref(string)
+        }
+
+        pub fn boxed_topic(string: &str) -> Box<wire::Topic> {
+            wire::Topic::from_boxed(string.into())
+        }
+    }
+
     #[test]
     fn simple() {
         let s = Simple::from_ref("simple");
@@ -332,6 +1209,9 @@ mod tests {
         let display = format!("{}", s);
         assert_eq!(display, "simple");
 
+        let padded = format!("{:>10}|{:-<8}|{:.3}", s, s, s);
+        assert_eq!(padded, "    simple|simple--|sim");
+
         let mut src = "mutable".to_string();
         let m = Simple::from_ref_mut(&mut src);
         m.as_mut().make_ascii_uppercase();
@@ -367,6 +1247,132 @@ mod tests {
         assert_eq!(arc.as_inner(), &[0, 1, 2, 3]);
     }
 
+    #[test]
+    fn array_eq() {
+        let bufw = SliceWrap::from_ref(&[0, 1, 2, 3]);
+        assert_eq!(bufw, &[0u8, 1, 2, 3]);
+        assert_eq!(&[0u8, 1, 2, 3], bufw);
+        assert!(bufw != &[0u8, 1, 2]);
+        assert!(&[0u8, 1, 2] != bufw);
+    }
+
+    #[test]
+    fn ctor_vis() {
+        assert_eq!(protocol::topic("a/b"), "a/b");
+        assert_eq!(protocol::boxed_topic("a/b").as_ref(), "a/b");
+    }
+
+    #[test]
+    fn sum_product() {
+        let nums = Numbers::from_ref(&[u32::MAX, 1, 2]);
+        assert_eq!(nums.sum::<u64>(), u32::MAX as u64 + 3);
+        assert_eq!(nums.product::<u64>(), u32::MAX as u64 * 2);
+        assert_eq!(Numbers::from_ref(&[]).sum::<u32>(), 0);
+    }
+
+    #[test]
+    fn into_vec() {
+        let bufw = SliceWrap::from_ref(&[0, 1, 2, 3]);
+        let vec: Vec<u8> = bufw.into();
+        assert_eq!(vec, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn hash_slice() {
+        let mut map = HashMap::new();
+        map.insert(HashSlice::from_boxed(vec![0, 1].into()), 1);
+        map.insert(HashSlice::from_boxed(vec![2, 3].into()), 2);
+
+        assert_eq!(map.get(&[0u8, 1][..]), Some(&1));
+        assert_eq!(map.get(&[2u8, 3][..]), Some(&2));
+        assert_eq!(map.get(&[4u8][..]), None);
+    }
+
+    #[test]
+    fn wrap_all() {
+        let boxed = shared::boxed_str("foo");
+        assert_eq!(&*boxed, "foo");
+
+        let arc = shared::arc_bytes(&[0, 1, 2]);
+        assert_eq!(&arc[..], &[0, 1, 2]);
+
+        let rc = shared::ProtoTopic::from_rc("a/b".into());
+        assert_eq!(&rc[..], "a/b");
+
+        // only `ctor_vis` is overridden, the shared `from` still applies
+        let boxed = shared::ProtoName::from_boxed("name".into());
+        assert_eq!(&boxed[..], "name");
+        let arc = shared::ProtoName::from_arc("name".into());
+        assert_eq!(&arc[..], "name");
+
+        let mut map = HashMap::new();
+        map.insert(properties::ClientId::from_boxed("client".into()), 1);
+        assert_eq!(map.get("client"), Some(&1));
+        let arc = properties::ResponseInfo::from_arc("info".into());
+        assert_eq!(&arc[..], "info");
+        let data = properties::AuthData::from_boxed(vec![0, 1].into());
+        assert_eq!(&data[..], &[0, 1]);
+        assert_eq!(properties::WillTopic::new("a/#").unwrap_err(), TopicError::Wildcard);
+    }
+
+    #[test]
+    fn hash_str() {
+        let mut map: HashMap<Box<MqttStr>, u32> = HashMap::new();
+        map.insert(MqttStr::from_ref("a/b").into(), 1);
+        map.insert(MqttStr::from_ref("c/d").into(), 2);
+
+        assert_eq!(map.get("a/b"), Some(&1));
+        assert_eq!(map.get("c/d"), Some(&2));
+        assert_eq!(map.get("e/f"), None);
+    }
+
+    #[test]
+    fn validate_topic_fn() {
+        assert_eq!(validate_topic("a/b"), Ok(()));
+        assert_eq!(validate_topic(""), Err(TopicError::Empty));
+        assert_eq!(validate_topic("a/#"), Err(TopicError::Wildcard));
+        assert_eq!(validate_topic("a/+/c"), Err(TopicError::Wildcard));
+    }
+
+    #[test]
+    fn validate_fn() {
+        assert_eq!(ValidTopic::new("a/b").unwrap(), "a/b");
+        assert_eq!(ValidTopic::new("").unwrap_err(), TopicError::Empty);
+
+        let topic: &ValidTopic = "a/b".try_into().unwrap();
+        assert_eq!(topic, "a/b");
+        let res: Result<&ValidTopic, _> = "a/#".try_into();
+        assert_eq!(res.unwrap_err(), TopicError::Wildcard);
+
+        let boxed = ValidTopic::new_boxed("a/b".into()).unwrap();
+        assert_eq!(&boxed[..], "a/b");
+        assert_eq!(ValidTopic::new_boxed("".into()).unwrap_err(), TopicError::Empty);
+
+        let rc = ValidTopic::new_rc("a/b".into()).unwrap();
+        assert_eq!(&rc[..], "a/b");
+        assert_eq!(ValidTopic::new_rc("#".into()).unwrap_err(), TopicError::Wildcard);
+
+        let arc = ValidTopic::new_arc("a/b".into()).unwrap();
+        assert_eq!(&arc[..], "a/b");
+        assert_eq!(ValidTopic::new_arc("".into()).unwrap_err(), TopicError::Empty);
+    }
+
+    #[test]
+    fn try_from_owned() {
+        let topic = Box::<ValidTopic>::try_from(String::from("a/b")).unwrap();
+        assert_eq!(&topic[..], "a/b");
+        let res = Box::<ValidTopic>::try_from(String::from("a/#"));
+        assert_eq!(res.unwrap_err(), TopicError::Wildcard);
+
+        let vec = vec![0, 1, 2, 3];
+        let ptr = vec.as_ptr();
+        let boxed = Box::<ShortBytes>::try_from(vec).unwrap();
+        assert_eq!(boxed.as_ptr(), ptr);
+        assert_eq!(&boxed[..], &[0, 1, 2, 3]);
+        let res = Box::<ShortBytes>::try_from(vec![0, 1, 2, 3, 4]);
+        assert_eq!(res.err(), Some(5));
+    }
+
     #[test]
     fn deref() {
         let bufw = SliceWrap::from_ref(&[0, 1, 2, 3]);
@@ -387,4 +1393,38 @@ mod tests {
 
         assert_eq!(super::as_deref!(opt), Some("FOO"));
     }
+
+    #[test]
+    fn as_deref_all() {
+        let opts = vec![Some(Simple::from_ref("foo")), None, Some(Simple::from_ref("bar"))];
+
+        let inner: Vec<Option<&str>> = super::as_deref_all!(&opts).collect();
+        assert_eq!(inner, [Some("foo"), None, Some("bar")]);
+
+        let inner: Vec<Option<&str>> = super::as_deref_all!(opts).collect();
+        assert_eq!(inner, [Some("foo"), None, Some("bar")]);
+    }
+
+    #[test]
+    fn try_map_deref() {
+        let opt = Some(Simple::from_ref("foo"));
+        let res = super::try_map_deref!(opt, Lowercase::new);
+        assert_eq!(res, Some(Ok(Lowercase::from_ref("foo"))));
+
+        let opt = Some(Simple::from_ref("Foo"));
+        let res = super::try_map_deref!(opt, |inner| Lowercase::new(inner));
+        assert_eq!(res, Some(Err("Foo")));
+
+        let opt: Option<&Simple> = None;
+        let res = super::try_map_deref!(opt, Lowercase::new);
+        assert_eq!(res.transpose(), Ok(None));
+
+        let mut string = String::from("foo");
+        let mut opt = Some(Simple::from_ref_mut(string.as_mut_str()));
+        if let Some(Some(lower)) = super::try_map_deref!(mut opt, Lowercase::new_mut) {
+            lower.as_inner_mut().make_ascii_uppercase();
+        }
+
+        assert_eq!(string, "FOO");
+    }
 }